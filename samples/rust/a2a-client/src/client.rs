@@ -0,0 +1,192 @@
+use a2a_core::jsonrpc::{
+    JsonRpcRequest, JsonRpcResponse, RequestId, StreamingResponse, TaskIdParams, TaskQueryParams,
+    TaskSendParams, JSONRPC_VERSION,
+};
+use a2a_core::types::{AgentCard, Task, TaskArtifactUpdateEvent, TaskStatusUpdateEvent};
+use futures::stream::{self, Stream, StreamExt};
+use std::io::BufRead;
+
+/// An event received while streaming a task's execution over SSE
+#[derive(Debug, Clone)]
+pub enum AgentEvent {
+    /// A new or updated artifact for the task
+    Artifact(TaskArtifactUpdateEvent),
+    /// A status update for the task
+    Status(TaskStatusUpdateEvent),
+}
+
+/// Client for calling another agent's A2A server
+///
+/// Fetches the remote `/agent-card` and issues JSON-RPC requests against the
+/// `/` and `/stream` endpoints, reusing the same `a2a_core::jsonrpc` types the
+/// server uses so the wire format stays in lockstep.
+pub struct A2AClient {
+    base_url: String,
+    http: reqwest::Client,
+    auth_token: Option<String>,
+}
+
+impl A2AClient {
+    /// Create a client for the agent hosted at `base_url`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            http: reqwest::Client::new(),
+            auth_token: None,
+        }
+    }
+
+    /// Authenticate requests with a bearer token
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    /// Fetch the remote agent's `AgentCard`
+    pub async fn agent_card(&self) -> anyhow::Result<AgentCard> {
+        let url = format!("{}/agent-card", self.base_url);
+        let card = self.http.get(url).send().await?.json().await?;
+        Ok(card)
+    }
+
+    /// Send a message, waiting for the (non-streamed) task response
+    pub async fn send_message(&self, params: TaskSendParams) -> anyhow::Result<Task> {
+        self.call("message/send", serde_json::to_value(params)?).await
+    }
+
+    /// Fetch a task by ID
+    pub async fn get_task(&self, id: impl Into<String>) -> anyhow::Result<Task> {
+        let params = TaskQueryParams {
+            base: TaskIdParams {
+                id: id.into(),
+                metadata: None,
+            },
+            history_length: None,
+        };
+        self.call("tasks/get", serde_json::to_value(params)?).await
+    }
+
+    /// Cancel a task by ID
+    pub async fn cancel_task(&self, id: impl Into<String>) -> anyhow::Result<Task> {
+        let params = TaskIdParams {
+            id: id.into(),
+            metadata: None,
+        };
+        self.call("tasks/cancel", serde_json::to_value(params)?).await
+    }
+
+    /// Send a message and stream the resulting `AgentEvent`s over SSE
+    pub async fn stream_message(
+        &self,
+        params: TaskSendParams,
+    ) -> anyhow::Result<impl Stream<Item = anyhow::Result<AgentEvent>>> {
+        let request = JsonRpcRequest {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(RequestId::String(uuid_like_id())),
+            method: "message/stream".to_string(),
+            params: Some(serde_json::to_value(params)?),
+        };
+
+        let mut req = self
+            .http
+            .post(format!("{}/stream", self.base_url))
+            .json(&request);
+        if let Some(token) = &self.auth_token {
+            req = req.bearer_auth(token);
+        }
+
+        let response = req.send().await?.error_for_status()?;
+        let bytes_stream = response.bytes_stream();
+
+        Ok(stream::unfold(
+            (bytes_stream, Vec::new()),
+            |(mut bytes_stream, mut buffer)| async move {
+                loop {
+                    if let Some(event) = take_sse_event(&mut buffer) {
+                        return Some((parse_streaming_event(event), (bytes_stream, buffer)));
+                    }
+                    match bytes_stream.next().await {
+                        Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+                        Some(Err(e)) => return Some((Err(e.into()), (bytes_stream, buffer))),
+                        None => return None,
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Issue a JSON-RPC request against `/` and deserialize its result into `T`
+    async fn call<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> anyhow::Result<T> {
+        let request = JsonRpcRequest {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(RequestId::String(uuid_like_id())),
+            method: method.to_string(),
+            params: Some(params),
+        };
+
+        let mut req = self.http.post(&self.base_url).json(&request);
+        if let Some(token) = &self.auth_token {
+            req = req.bearer_auth(token);
+        }
+
+        let response: JsonRpcResponse = req.send().await?.error_for_status()?.json().await?;
+
+        match (response.result, response.error) {
+            (Some(result), _) => Ok(serde_json::from_value(result)?),
+            (None, Some(error)) => Err(anyhow::anyhow!(
+                "JSON-RPC error {}: {}",
+                error.code,
+                error.message
+            )),
+            (None, None) => Err(anyhow::anyhow!("JSON-RPC response had no result or error")),
+        }
+    }
+}
+
+/// Extract the next complete SSE frame's `data:` payload from `buffer`, if any,
+/// draining the consumed bytes from the front of `buffer`.
+fn take_sse_event(buffer: &mut Vec<u8>) -> Option<String> {
+    let boundary = buffer.windows(2).position(|w| w == b"\n\n")?;
+    let frame: Vec<u8> = buffer.drain(..boundary + 2).collect();
+
+    let mut data = String::new();
+    for line in frame.lines().map_while(Result::ok) {
+        if let Some(payload) = line.strip_prefix("data:") {
+            data.push_str(payload.trim_start());
+        }
+    }
+    Some(data)
+}
+
+/// Parse an SSE `data:` payload into an `AgentEvent`
+fn parse_streaming_event(data: String) -> anyhow::Result<AgentEvent> {
+    let response: StreamingResponse = serde_json::from_str(&data)?;
+
+    if let Some(error) = response.error {
+        return Err(anyhow::anyhow!("Agent event error: {}", error.message));
+    }
+
+    let result = response
+        .result
+        .ok_or_else(|| anyhow::anyhow!("Streaming response had neither result nor error"))?;
+
+    if let Ok(status) = serde_json::from_value::<TaskStatusUpdateEvent>(result.clone()) {
+        return Ok(AgentEvent::Status(status));
+    }
+    let artifact: TaskArtifactUpdateEvent = serde_json::from_value(result)?;
+    Ok(AgentEvent::Artifact(artifact))
+}
+
+/// Generate a request ID without pulling in a UUID dependency
+fn uuid_like_id() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("req-{nanos}")
+}
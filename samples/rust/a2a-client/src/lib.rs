@@ -0,0 +1,39 @@
+//! # A2A Client
+//!
+//! Client for calling other agents over the Agent2Agent (A2A) protocol.
+//!
+//! This crate lets an agent act as a client of another A2A server, so it can
+//! fan work out to specialized downstream agents:
+//!
+//! - `A2AClient`: fetches a remote agent's card and issues JSON-RPC requests
+//! - `AgentEvent`: events yielded while streaming a task via `stream_message`
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use a2a_client::A2AClient;
+//! use a2a_core::jsonrpc::TaskSendParams;
+//! use a2a_core::types::Message;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let client = A2AClient::new("http://localhost:9999");
+//!     let task = client
+//!         .send_message(TaskSendParams {
+//!             id: "task-1".to_string(),
+//!             session_id: None,
+//!             message: Message::user_text("hello"),
+//!             push_notification: None,
+//!             history_length: None,
+//!             metadata: None,
+//!         })
+//!         .await?;
+//!     println!("{:?}", task);
+//!     Ok(())
+//! }
+//! ```
+
+pub mod client;
+
+// Re-export commonly used types
+pub use client::{A2AClient, AgentEvent};
@@ -9,17 +9,45 @@ pub const JSONRPC_VERSION: &str = "2.0";
 /// JSON-RPC error codes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorCode {
-    ParseError = -32700,
-    InvalidRequest = -32600,
-    MethodNotFound = -32601,
-    InvalidParams = -32602,
-    InternalError = -32603,
-    TaskNotFound = -32001,
+    ParseError,
+    InvalidRequest,
+    MethodNotFound,
+    InvalidParams,
+    InternalError,
+    TaskNotFound,
+    Unauthorized,
+    /// A numeric code not recognized by this version of the crate, preserved verbatim
+    /// so round-tripping an error from a newer peer doesn't lose information
+    Unknown(i32),
 }
 
 impl ErrorCode {
     pub fn as_i32(self) -> i32 {
-        self as i32
+        match self {
+            Self::ParseError => -32700,
+            Self::InvalidRequest => -32600,
+            Self::MethodNotFound => -32601,
+            Self::InvalidParams => -32602,
+            Self::InternalError => -32603,
+            Self::TaskNotFound => -32001,
+            Self::Unauthorized => -32002,
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// Map a raw JSON-RPC error code into its typed `ErrorCode`, falling back to
+    /// `Unknown` for codes this crate doesn't recognize
+    pub fn from_i32(code: i32) -> Self {
+        match code {
+            -32700 => Self::ParseError,
+            -32600 => Self::InvalidRequest,
+            -32601 => Self::MethodNotFound,
+            -32602 => Self::InvalidParams,
+            -32603 => Self::InternalError,
+            -32001 => Self::TaskNotFound,
+            -32002 => Self::Unauthorized,
+            other => Self::Unknown(other),
+        }
     }
 }
 
@@ -55,6 +83,23 @@ pub struct JsonRpcRequest {
     pub params: Option<Value>,
 }
 
+/// A JSON-RPC request body, which per the spec is either a single request object
+/// or a batch: a JSON array of request objects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonRpcMessage {
+    Single(JsonRpcRequest),
+    Batch(Vec<JsonRpcRequest>),
+}
+
+/// A JSON-RPC response body, matching the shape of the `JsonRpcMessage` it answers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonRpcResponseMessage {
+    Single(JsonRpcResponse),
+    Batch(Vec<JsonRpcResponse>),
+}
+
 /// JSON-RPC error object
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsonRpcError {
@@ -101,6 +146,16 @@ impl JsonRpcError {
     pub fn task_not_found(message: impl Into<String>) -> Self {
         Self::new(ErrorCode::TaskNotFound, message)
     }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Unauthorized, message)
+    }
+
+    /// The error's code as a typed `ErrorCode`, preserving an unrecognized numeric
+    /// code via `ErrorCode::Unknown` instead of losing it
+    pub fn code_kind(&self) -> ErrorCode {
+        ErrorCode::from_i32(self.code)
+    }
 }
 
 /// JSON-RPC response
@@ -187,6 +242,16 @@ pub struct PushNotificationConfig {
     pub token: Option<String>,
 }
 
+/// Parameters for setting or the response describing a task's push notification config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskPushNotificationConfig {
+    /// Unique identifier of the task
+    pub id: String,
+    /// Webhook configuration to notify on task updates
+    pub push_notification_config: PushNotificationConfig,
+}
+
 /// Response for task operations (with optional history)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
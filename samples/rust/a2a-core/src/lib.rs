@@ -49,8 +49,9 @@ pub mod types;
 // Re-export commonly used types
 pub use error::{A2AError, Result};
 pub use jsonrpc::{
-    ErrorCode, JsonRpcError, JsonRpcRequest, JsonRpcResponse, RequestId, TaskIdParams,
-    TaskQueryParams, TaskSendParams, JSONRPC_VERSION,
+    ErrorCode, JsonRpcError, JsonRpcMessage, JsonRpcRequest, JsonRpcResponse,
+    JsonRpcResponseMessage, PushNotificationConfig, RequestId, TaskIdParams,
+    TaskPushNotificationConfig, TaskQueryParams, TaskSendParams, JSONRPC_VERSION,
 };
 pub use types::{
     AgentCapabilities, AgentCard, AgentSkill, Artifact, Message, Part, Task, TaskState,
@@ -1,9 +1,10 @@
+use serde::de::Deserializer;
+use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Task state within the A2A protocol
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskState {
     Submitted,
     Working,
@@ -11,7 +12,44 @@ pub enum TaskState {
     Completed,
     Canceled,
     Failed,
-    Unknown,
+    /// A state string not recognized by this version of the crate, preserved verbatim
+    /// so round-tripping a message from a newer peer doesn't lose information
+    Unknown(String),
+}
+
+impl TaskState {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Submitted => "submitted",
+            Self::Working => "working",
+            Self::InputRequired => "input-required",
+            Self::Completed => "completed",
+            Self::Canceled => "canceled",
+            Self::Failed => "failed",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
+impl Serialize for TaskState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TaskState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "submitted" => Self::Submitted,
+            "working" => Self::Working,
+            "input-required" => Self::InputRequired,
+            "completed" => Self::Completed,
+            "canceled" => Self::Canceled,
+            "failed" => Self::Failed,
+            _ => Self::Unknown(s),
+        })
+    }
 }
 
 /// Authentication schemes and credentials for an agent
@@ -209,6 +247,10 @@ pub struct Artifact {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskStatus {
     pub state: TaskState,
+    /// Message associated with this status, e.g. the agent's prompt when
+    /// `state` is `InputRequired`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
 }
 
 /// An A2A task
@@ -227,6 +269,7 @@ impl Task {
             id,
             status: TaskStatus {
                 state: TaskState::Working,
+                message: None,
             },
             artifacts: None,
         }
@@ -244,6 +287,13 @@ impl Task {
         self
     }
 
+    /// Mark task as awaiting more input, surfacing the agent's prompt
+    pub fn await_input(mut self, prompt: Message) -> Self {
+        self.status.state = TaskState::InputRequired;
+        self.status.message = Some(prompt);
+        self
+    }
+
     /// Add an artifact to the task
     pub fn with_artifact(mut self, artifact: Artifact) -> Self {
         self.artifacts
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Validates the bearer token presented on a protected request
+///
+/// Implement this trait to back authentication with a static allowlist, a shared
+/// secret, or an external token-introspection service.
+#[async_trait]
+pub trait TokenValidator: Send + Sync {
+    /// Return true if the token should be allowed through
+    async fn validate(&self, token: &str) -> bool;
+}
+
+/// Type alias for Arc-wrapped token validator
+pub type TokenValidatorRef = Arc<dyn TokenValidator>;
+
+/// Validates against a fixed set of accepted bearer tokens
+pub struct StaticTokenValidator {
+    tokens: HashSet<String>,
+}
+
+impl StaticTokenValidator {
+    /// Create a validator that accepts any token in the given set
+    pub fn new(tokens: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            tokens: tokens.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenValidator for StaticTokenValidator {
+    async fn validate(&self, token: &str) -> bool {
+        self.tokens.contains(token)
+    }
+}
@@ -1,7 +1,9 @@
 use a2a_core::types::{Message, Task};
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, RwLock};
 
 /// Events that can be emitted during agent execution
 #[derive(Debug, Clone)]
@@ -14,6 +16,14 @@ pub enum AgentEvent {
     Completed(Task),
     /// Task failed
     Failed(String),
+    /// The agent is pausing to ask the client for more input. `correlation_id`
+    /// identifies this particular wait, so a follow-up `tasks/send` for the same
+    /// task can be routed back to the executor blocked in `PendingInputs::wait_for_input`.
+    InputRequired {
+        task_id: String,
+        prompt: Message,
+        correlation_id: String,
+    },
 }
 
 /// Event queue for agent execution
@@ -52,10 +62,144 @@ impl EventQueue {
     pub async fn send_failed(&self, error: String) -> anyhow::Result<()> {
         self.send(AgentEvent::Failed(error)).await
     }
+
+    /// Send an input-required event, pausing the task and surfacing `prompt` to
+    /// the client. Prefer `RequestContext::request_input`, which calls this
+    /// after registering the wait so a fast client's follow-up can't race
+    /// ahead of it; call this directly only if you need to register the wait
+    /// yourself first via `PendingInputs`.
+    pub async fn send_input_required(
+        &self,
+        task_id: String,
+        prompt: Message,
+        correlation_id: String,
+    ) -> anyhow::Result<()> {
+        self.send(AgentEvent::InputRequired {
+            task_id,
+            prompt,
+            correlation_id,
+        })
+        .await
+    }
+}
+
+/// How a pending `wait_for_input` call was resolved
+enum ResumeSignal {
+    /// A follow-up `tasks/send` arrived for the waiting task
+    Input(Message),
+    /// The task was canceled while the executor was waiting
+    Canceled,
+}
+
+/// Registry of in-flight `AgentEvent::InputRequired` waits, keyed by correlation
+/// id. Shared between the executor (which waits on it) and the request handler
+/// (which resumes or cancels a wait as follow-up `tasks/send`/`tasks/cancel`
+/// calls arrive).
+#[derive(Clone, Default)]
+pub struct PendingInputs {
+    waiters: Arc<RwLock<HashMap<String, (String, oneshot::Sender<ResumeSignal>)>>>,
+}
+
+impl PendingInputs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a wait, keyed by `correlation_id`, for a follow-up `tasks/send`
+    /// for `task_id`, returning a token to block on with `await_resume`. Registering before
+    /// the paired `AgentEvent::InputRequired` is emitted (see
+    /// `RequestContext::request_input`) is what prevents a fast client's
+    /// follow-up from racing ahead of the wait being set up.
+    async fn register(
+        &self,
+        task_id: impl Into<String>,
+        correlation_id: impl Into<String>,
+    ) -> oneshot::Receiver<ResumeSignal> {
+        let (tx, rx) = oneshot::channel();
+        self.waiters
+            .write()
+            .await
+            .insert(correlation_id.into(), (task_id.into(), tx));
+        rx
+    }
+
+    /// Block on a wait previously set up with `register`, for up to `timeout`.
+    /// Returns an error on timeout or cancellation rather than blocking forever.
+    async fn await_resume(
+        &self,
+        correlation_id: &str,
+        rx: oneshot::Receiver<ResumeSignal>,
+        timeout: Duration,
+    ) -> anyhow::Result<Message> {
+        let outcome = tokio::time::timeout(timeout, rx).await;
+        self.waiters.write().await.remove(correlation_id);
+
+        match outcome {
+            Ok(Ok(ResumeSignal::Input(message))) => Ok(message),
+            Ok(Ok(ResumeSignal::Canceled)) => {
+                Err(anyhow::anyhow!("task was canceled while waiting for input"))
+            }
+            Ok(Err(_)) => Err(anyhow::anyhow!("input waiter dropped without a response")),
+            Err(_) => Err(anyhow::anyhow!(
+                "timed out after {:?} waiting for input (correlation_id: {})",
+                timeout,
+                correlation_id
+            )),
+        }
+    }
+
+    /// Wait up to `timeout` for a follow-up `tasks/send` for `task_id`
+    /// (registered under `correlation_id`), or for the task to be canceled. Returns an error on
+    /// timeout or cancellation rather than blocking forever.
+    ///
+    /// Only useful if you've already emitted the paired `AgentEvent::InputRequired`
+    /// — prefer `RequestContext::request_input`, which avoids the race between
+    /// the two by registering the wait first.
+    pub async fn wait_for_input(
+        &self,
+        task_id: impl Into<String>,
+        correlation_id: impl Into<String>,
+        timeout: Duration,
+    ) -> anyhow::Result<Message> {
+        let correlation_id = correlation_id.into();
+        let rx = self.register(task_id, correlation_id.clone()).await;
+        self.await_resume(&correlation_id, rx, timeout).await
+    }
+
+    /// Resume whichever wait is registered for `task_id` with a follow-up
+    /// message. Returns `true` if a waiter was registered for it and received
+    /// the message.
+    pub async fn resume_task(&self, task_id: &str, message: Message) -> bool {
+        let mut waiters = self.waiters.write().await;
+        let correlation_id = waiters
+            .iter()
+            .find(|(_, (waiting_task_id, _))| waiting_task_id == task_id)
+            .map(|(correlation_id, _)| correlation_id.clone());
+
+        match correlation_id.and_then(|id| waiters.remove(&id)) {
+            Some((_, tx)) => tx.send(ResumeSignal::Input(message)).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Resolve every wait registered for `task_id` as canceled
+    pub async fn cancel_for_task(&self, task_id: &str) {
+        let mut waiters = self.waiters.write().await;
+        let canceled: Vec<String> = waiters
+            .iter()
+            .filter(|(_, (waiting_task_id, _))| waiting_task_id == task_id)
+            .map(|(correlation_id, _)| correlation_id.clone())
+            .collect();
+        for correlation_id in canceled {
+            if let Some((_, tx)) = waiters.remove(&correlation_id) {
+                let _ = tx.send(ResumeSignal::Canceled);
+            }
+        }
+    }
 }
 
 /// Context for agent execution
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RequestContext {
     /// Task ID
     pub task_id: String,
@@ -65,6 +209,50 @@ pub struct RequestContext {
     pub message: Message,
     /// Message history
     pub history: Vec<Message>,
+    /// Registry for pausing on `AgentEvent::InputRequired` and resuming once a
+    /// matching follow-up `tasks/send` arrives
+    pub pending_inputs: PendingInputs,
+}
+
+impl RequestContext {
+    /// Pause this task for more input, surfacing `prompt` to the client.
+    /// Registers the wait on `pending_inputs` *before* emitting
+    /// `AgentEvent::InputRequired` through `event_queue`, so a fast client's
+    /// follow-up `tasks/send` — which can arrive the instant the task's
+    /// status is visible as `InputRequired` — can never race ahead of the
+    /// executor starting to wait for it. Returns the follow-up message, or an
+    /// error if `timeout` elapses or the task is canceled first.
+    pub async fn request_input(
+        &self,
+        event_queue: &EventQueue,
+        prompt: Message,
+        timeout: Duration,
+    ) -> anyhow::Result<Message> {
+        let correlation_id = next_correlation_id(&self.task_id);
+        let rx = self
+            .pending_inputs
+            .register(self.task_id.clone(), correlation_id.clone())
+            .await;
+
+        event_queue
+            .send_input_required(self.task_id.clone(), prompt, correlation_id.clone())
+            .await?;
+
+        self.pending_inputs
+            .await_resume(&correlation_id, rx, timeout)
+            .await
+    }
+}
+
+/// Generate a correlation id for an input-required wait, without pulling in a
+/// UUID dependency
+fn next_correlation_id(task_id: &str) -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("{task_id}-input-{nanos}")
 }
 
 /// Trait for implementing agent executors
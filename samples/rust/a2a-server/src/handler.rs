@@ -1,86 +1,250 @@
-use crate::executor::{AgentEvent, EventQueue, RequestContext};
+use crate::executor::{AgentEvent, EventQueue, PendingInputs, RequestContext};
+use crate::notifier::{PushNotificationSenderRef, PushNotifier, TaskNotification};
+use crate::registry::{
+    parse_params, ErrorLike, Method, MethodRef, MethodRegistry, Params, RouterState, State,
+    TypedMethod,
+};
 use crate::store::TaskStoreRef;
 use a2a_core::jsonrpc::{
-    JsonRpcError, JsonRpcRequest, JsonRpcResponse, RequestId, TaskIdParams, TaskQueryParams,
+    JsonRpcError, JsonRpcMessage, JsonRpcRequest, JsonRpcResponse, JsonRpcResponseMessage,
+    RequestId, StreamingResponse, TaskIdParams, TaskPushNotificationConfig, TaskQueryParams,
     TaskSendParams,
 };
-use a2a_core::types::{Artifact, Message, Task, TaskState};
+use a2a_core::types::{
+    Artifact, Message, Task, TaskArtifactUpdateEvent, TaskState, TaskStatus, TaskStatusUpdateEvent,
+};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, oneshot, RwLock};
 
 use crate::executor::AgentExecutorRef;
 
+/// Capacity of the per-task broadcast channel backing SSE streams
+const STREAM_CHANNEL_CAPACITY: usize = 256;
+
+/// Default ceiling on how long `message/send` waits for the agent to reach a
+/// terminal state before returning the task in whatever state it's currently in
+pub const DEFAULT_SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Callbacks fired the next time a task reaches a terminal state or pauses for
+/// input (`InputRequired`), keyed by task id
+type DoneWaiters = Arc<RwLock<HashMap<String, Vec<oneshot::Sender<()>>>>>;
+
 /// Request handler for A2A JSON-RPC requests
 pub struct RequestHandler {
     executor: AgentExecutorRef,
     task_store: TaskStoreRef,
+    /// Live event broadcasters for in-flight streamed tasks, keyed by task id
+    streams: Arc<RwLock<HashMap<String, broadcast::Sender<AgentEvent>>>>,
+    /// Delivers task updates to registered push notification webhooks
+    notifier: PushNotificationSenderRef,
+    /// Dispatch table for single-response JSON-RPC methods
+    registry: MethodRegistry,
+    /// How long `message/send` waits for the agent to reach a terminal state
+    send_timeout: Duration,
+    /// In-flight `AgentEvent::InputRequired` waits, shared with every `RequestContext`
+    pending_inputs: PendingInputs,
+    /// Callbacks fired the next time a task reaches a terminal state or pauses
+    /// for input, keyed by task id
+    done_waiters: DoneWaiters,
 }
 
 impl RequestHandler {
     pub fn new(executor: AgentExecutorRef, task_store: TaskStoreRef) -> Self {
+        Self::with_send_timeout(executor, task_store, DEFAULT_SEND_TIMEOUT)
+    }
+
+    pub fn with_send_timeout(
+        executor: AgentExecutorRef,
+        task_store: TaskStoreRef,
+        send_timeout: Duration,
+    ) -> Self {
+        let mut registry = MethodRegistry::new();
+        registry.register("message/send", Arc::new(MessageSend));
+        // `tasks/send` is accepted as an alias of `message/send`, so a follow-up
+        // to an `InputRequired` task can use either name
+        registry.register("tasks/send", Arc::new(MessageSend));
+        registry.register("tasks/get", Arc::new(TaskGet));
+        registry.register("tasks/cancel", Arc::new(TaskCancel));
+        registry.register("tasks/pushNotificationConfig/set", Arc::new(SetPushConfig));
+        registry.register("tasks/pushNotificationConfig/get", Arc::new(GetPushConfig));
+
         Self {
             executor,
             task_store,
+            streams: Arc::new(RwLock::new(HashMap::new())),
+            notifier: Arc::new(PushNotifier::new()),
+            registry,
+            send_timeout,
+            pending_inputs: PendingInputs::new(),
+            done_waiters: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
-    /// Handle a JSON-RPC request
+    /// Register to be notified the next time `task_id` reaches a terminal state
+    /// or pauses for input (`InputRequired`)
+    async fn await_completion(&self, task_id: &str) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+        self.done_waiters
+            .write()
+            .await
+            .entry(task_id.to_string())
+            .or_insert_with(Vec::new)
+            .push(tx);
+        rx
+    }
+
+    /// Register a custom JSON-RPC method, overriding any built-in with the same name
+    pub fn register_method(&mut self, name: impl Into<String>, method: MethodRef) {
+        self.registry.register(name, method);
+    }
+
+    /// Register a typed JSON-RPC method from a plain async function of the form
+    /// `async fn(Params<T>, State<RouterState>) -> Result<R, E>`, overriding any
+    /// built-in with the same name. `params` is deserialized into `T` and the
+    /// handler gets a `State<RouterState>` holding this handler's executor and
+    /// task store, so adding a new A2A method is a one-function change rather
+    /// than a new `Method` impl.
+    pub fn register_typed_method<F, T, R, E, Fut>(&mut self, name: impl Into<String>, handler: F)
+    where
+        F: Fn(Params<T>, State<RouterState>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        T: DeserializeOwned + Send + 'static,
+        R: Serialize + Send + 'static,
+        E: ErrorLike + Send + 'static,
+    {
+        let state = State(Arc::new(RouterState {
+            executor: Arc::clone(&self.executor),
+            task_store: Arc::clone(&self.task_store),
+        }));
+        self.registry
+            .register(name, Arc::new(TypedMethod::new(handler, state)));
+    }
+
+    /// Replace the push notification transport, overriding the default `PushNotifier`
+    pub fn set_notifier(&mut self, notifier: PushNotificationSenderRef) {
+        self.notifier = notifier;
+    }
+
+    /// The agent executor backing the built-in methods
+    pub fn executor(&self) -> &AgentExecutorRef {
+        &self.executor
+    }
+
+    /// The task store backing the built-in methods
+    pub fn task_store(&self) -> &TaskStoreRef {
+        &self.task_store
+    }
+
+    /// Handle a JSON-RPC request by dispatching through the method registry
     pub async fn handle(&self, request: JsonRpcRequest) -> JsonRpcResponse {
         let id = request.id.clone().unwrap_or(RequestId::Null);
 
-        match request.method.as_str() {
-            "message/send" => self.handle_message_send(request, id).await,
-            "tasks/get" => self.handle_task_get(request, id).await,
-            "tasks/cancel" => self.handle_task_cancel(request, id).await,
-            _ => JsonRpcResponse::error(
+        match self.registry.get(&request.method) {
+            Some(method) => match method.call(request.params, self).await {
+                Ok(value) => JsonRpcResponse::success(id, value),
+                Err(error) => JsonRpcResponse::error(id, error),
+            },
+            None => JsonRpcResponse::error(
                 id,
                 JsonRpcError::method_not_found(format!("Method not found: {}", request.method)),
             ),
         }
     }
 
-    async fn handle_message_send(
+    /// Handle a single-or-batch JSON-RPC request per the spec's batch rules: each
+    /// element is processed independently, notifications (requests with no `id`)
+    /// produce no response entry, an empty batch is itself an invalid request, and a
+    /// batch containing only notifications yields no response body at all (`None`).
+    pub async fn handle_message(
         &self,
-        request: JsonRpcRequest,
-        id: RequestId,
-    ) -> JsonRpcResponse {
-        // Parse parameters
-        let params: TaskSendParams = match request.params {
-            Some(params) => match serde_json::from_value(params) {
-                Ok(p) => p,
-                Err(e) => {
-                    return JsonRpcResponse::error(
-                        id,
-                        JsonRpcError::invalid_params(format!("Invalid parameters: {}", e)),
-                    )
+        message: JsonRpcMessage,
+    ) -> Option<JsonRpcResponseMessage> {
+        match message {
+            JsonRpcMessage::Single(request) => {
+                let is_notification = request.id.is_none();
+                let response = self.handle(request).await;
+                (!is_notification).then_some(JsonRpcResponseMessage::Single(response))
+            }
+            JsonRpcMessage::Batch(requests) => {
+                if requests.is_empty() {
+                    return Some(JsonRpcResponseMessage::Single(JsonRpcResponse::error(
+                        RequestId::Null,
+                        JsonRpcError::invalid_request("Batch request must not be empty"),
+                    )));
                 }
-            },
-            None => {
-                return JsonRpcResponse::error(
-                    id,
-                    JsonRpcError::invalid_params("Missing parameters"),
-                )
+
+                let mut responses = Vec::new();
+                for request in requests {
+                    let is_notification = request.id.is_none();
+                    let response = self.handle(request).await;
+                    if !is_notification {
+                        responses.push(response);
+                    }
+                }
+
+                (!responses.is_empty()).then_some(JsonRpcResponseMessage::Batch(responses))
             }
-        };
+        }
+    }
+
+    /// Handle a streaming JSON-RPC request (`message/stream` or `tasks/resubscribe`)
+    ///
+    /// Returns a live receiver of `AgentEvent`s for the task, or a `JsonRpcResponse`
+    /// error if the request couldn't be set up.
+    pub async fn handle_stream(
+        &self,
+        request: JsonRpcRequest,
+    ) -> Result<(String, broadcast::Receiver<AgentEvent>), JsonRpcResponse> {
+        let id = request.id.clone().unwrap_or(RequestId::Null);
+
+        match request.method.as_str() {
+            "message/stream" => self.handle_message_stream(request, id).await,
+            "tasks/resubscribe" => self.handle_tasks_resubscribe(request, id).await,
+            _ => Err(JsonRpcResponse::error(
+                id,
+                JsonRpcError::method_not_found(format!("Method not found: {}", request.method)),
+            )),
+        }
+    }
+
+    async fn message_send(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: TaskSendParams = parse_params(params)?;
+
+        // A task awaiting more input isn't a new execution: route the follow-up
+        // back to the executor parked in `PendingInputs::wait_for_input`.
+        if let Ok(Some(existing)) = self.task_store.get_task(&params.id).await {
+            if existing.status.state == TaskState::InputRequired {
+                return self.resume_task(params).await;
+            }
+        }
 
         // Create task
         let mut task = Task::new(params.id.clone());
 
         // Store initial message
-        if let Err(e) = self.task_store.store_message(&task.id, params.message.clone()).await {
-            return JsonRpcResponse::error(
-                id,
-                JsonRpcError::internal_error(format!("Failed to store message: {}", e)),
-            );
-        }
+        self.task_store
+            .store_message(&task.id, params.message.clone())
+            .await
+            .map_err(|e| JsonRpcError::internal_error(format!("Failed to store message: {}", e)))?;
 
         // Store task
-        if let Err(e) = self.task_store.store_task(task.clone()).await {
-            return JsonRpcResponse::error(
-                id,
-                JsonRpcError::internal_error(format!("Failed to store task: {}", e)),
-            );
-        }
+        self.task_store
+            .store_task(task.clone())
+            .await
+            .map_err(|e| JsonRpcError::internal_error(format!("Failed to store task: {}", e)))?;
+
+        // An inline push notification config on the creating request registers
+        // the same way `tasks/pushNotificationConfig/set` would, so `Completed`/
+        // `Failed`/`InputRequired` events for this task notify it.
+        self.store_inline_push_config(&task.id, &params.push_notification)
+            .await?;
 
         // Get history
         let history = self
@@ -95,174 +259,554 @@ impl RequestHandler {
             session_id: params.session_id.clone(),
             message: params.message.clone(),
             history,
+            pending_inputs: self.pending_inputs.clone(),
         };
 
         // Create event queue
-        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (tx, rx) = mpsc::unbounded_channel();
         let event_queue = EventQueue::new(tx);
 
         // Clone executor and task store for the async task
         let executor = Arc::clone(&self.executor);
         let task_store = Arc::clone(&self.task_store);
+        let notifier = Arc::clone(&self.notifier);
+        let done_waiters = Arc::clone(&self.done_waiters);
         let task_id = params.id.clone();
 
-        // Execute agent in background
-        tokio::spawn(async move {
-            if let Err(e) = executor.execute(context, event_queue).await {
-                tracing::error!("Agent execution failed: {}", e);
-            }
+        // Signaled by the background loop once the task reaches a terminal state
+        let done_rx = self.await_completion(&task_id).await;
 
-            // Process events
-            while let Some(event) = rx.recv().await {
-                match event {
-                    AgentEvent::Message(msg) => {
-                        // Store message
-                        if let Err(e) = task_store.store_message(&task_id, msg.clone()).await {
-                            tracing::error!("Failed to store message: {}", e);
-                        }
-
-                        // Update task with artifact
-                        if let Ok(Some(mut task)) = task_store.get_task(&task_id).await {
-                            let artifact = message_to_artifact(msg);
-                            task = task.with_artifact(artifact);
-                            if let Err(e) = task_store.update_task(task).await {
-                                tracing::error!("Failed to update task: {}", e);
-                            }
-                        }
-                    }
-                    AgentEvent::StatusUpdate(updated_task) => {
-                        if let Err(e) = task_store.update_task(updated_task).await {
-                            tracing::error!("Failed to update task: {}", e);
-                        }
-                    }
-                    AgentEvent::Completed(completed_task) => {
-                        if let Err(e) = task_store.update_task(completed_task).await {
-                            tracing::error!("Failed to update task: {}", e);
-                        }
-                        break;
-                    }
-                    AgentEvent::Failed(error) => {
-                        if let Ok(Some(mut task)) = task_store.get_task(&task_id).await {
-                            task.status.state = TaskState::Failed;
-                            if let Err(e) = task_store.update_task(task).await {
-                                tracing::error!("Failed to update task: {}", e);
-                            }
-                        }
-                        tracing::error!("Task failed: {}", error);
-                        break;
-                    }
-                }
-            }
-        });
+        // Execute agent in background
+        tokio::spawn(process_agent_events(
+            executor,
+            task_store,
+            notifier,
+            context,
+            event_queue,
+            rx,
+            task_id,
+            None,
+            done_waiters,
+        ));
 
-        // Wait a bit for the task to be updated
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        // Wait for the agent to finish, but don't hold the request open forever
+        if tokio::time::timeout(self.send_timeout, done_rx).await.is_err() {
+            tracing::warn!(
+                "message/send timed out waiting for task {} to finish after {:?}",
+                params.id,
+                self.send_timeout
+            );
+        }
 
-        // Get updated task
+        // Get updated task, falling back to the task as last known locally if the
+        // store has no entry for it (e.g. purged by a TTL sweep while we waited)
         task = self
             .task_store
             .get_task(&params.id)
             .await
-            .unwrap_or(Some(task))
-            .unwrap();
+            .unwrap_or(Some(task.clone()))
+            .unwrap_or(task);
+
+        Ok(serde_json::to_value(&task).unwrap())
+    }
+
+    /// Persist a task-creation request's inline `pushNotification` config, if
+    /// any, the same way `tasks/pushNotificationConfig/set` would, so the task's
+    /// own events notify it without a separate RPC call.
+    async fn store_inline_push_config(
+        &self,
+        task_id: &str,
+        push_notification: &Option<a2a_core::jsonrpc::PushNotificationConfig>,
+    ) -> Result<(), JsonRpcError> {
+        let Some(config) = push_notification else {
+            return Ok(());
+        };
+        self.task_store
+            .set_push_config(task_id, config.clone())
+            .await
+            .map_err(|e| {
+                JsonRpcError::internal_error(format!("Failed to store push config: {}", e))
+            })
+    }
+
+    /// Resume a task that's parked in `InputRequired`, handing the follow-up
+    /// message to the executor waiting in `PendingInputs::wait_for_input` and
+    /// waiting again (up to `send_timeout`) for it to reach a terminal state or
+    /// to pause for input again.
+    async fn resume_task(&self, params: TaskSendParams) -> Result<Value, JsonRpcError> {
+        self.task_store
+            .store_message(&params.id, params.message.clone())
+            .await
+            .map_err(|e| {
+                JsonRpcError::internal_error(format!("Failed to store follow-up message: {}", e))
+            })?;
+
+        let done_rx = self.await_completion(&params.id).await;
+
+        if !self
+            .pending_inputs
+            .resume_task(&params.id, params.message.clone())
+            .await
+        {
+            return Err(JsonRpcError::internal_error(format!(
+                "Task {} is input-required but no executor is waiting for it",
+                params.id
+            )));
+        }
+
+        if tokio::time::timeout(self.send_timeout, done_rx).await.is_err() {
+            tracing::warn!(
+                "tasks/send resume timed out waiting for task {} to finish after {:?}",
+                params.id,
+                self.send_timeout
+            );
+        }
+
+        match self.task_store.get_task(&params.id).await {
+            Ok(Some(task)) => Ok(serde_json::to_value(&task).unwrap()),
+            Ok(None) => Err(JsonRpcError::task_not_found("Task not found")),
+            Err(e) => Err(JsonRpcError::internal_error(format!(
+                "Failed to get task: {}",
+                e
+            ))),
+        }
+    }
+
+    async fn task_get(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: TaskQueryParams = parse_params(params)?;
+
+        match self.task_store.get_task(&params.base.id).await {
+            Ok(Some(task)) => Ok(serde_json::to_value(&task).unwrap()),
+            Ok(None) => Err(JsonRpcError::task_not_found("Task not found")),
+            Err(e) => Err(JsonRpcError::internal_error(format!(
+                "Failed to get task: {}",
+                e
+            ))),
+        }
+    }
+
+    async fn task_cancel(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: TaskIdParams = parse_params(params)?;
+
+        // Get task
+        let mut task = match self.task_store.get_task(&params.id).await {
+            Ok(Some(task)) => task,
+            Ok(None) => return Err(JsonRpcError::task_not_found("Task not found")),
+            Err(e) => {
+                return Err(JsonRpcError::internal_error(format!(
+                    "Failed to get task: {}",
+                    e
+                )))
+            }
+        };
+
+        // Update task status
+        task.status.state = TaskState::Canceled;
+
+        // Store updated task
+        self.task_store
+            .update_task(task.clone())
+            .await
+            .map_err(|e| JsonRpcError::internal_error(format!("Failed to update task: {}", e)))?;
+
+        // If the executor is parked in `wait_for_input`, unblock it with an error
+        // instead of leaving it waiting on a task that's no longer running.
+        self.pending_inputs.cancel_for_task(&params.id).await;
+
+        // Call executor cancel
+        let history = self
+            .task_store
+            .get_history(&params.id)
+            .await
+            .unwrap_or_default();
+
+        let context = RequestContext {
+            task_id: params.id.clone(),
+            session_id: None,
+            message: Message::new("system").with_text("cancel"),
+            history,
+            pending_inputs: self.pending_inputs.clone(),
+        };
+
+        if let Err(e) = self.executor.cancel(context).await {
+            tracing::error!("Failed to cancel task: {}", e);
+        }
+
+        Ok(serde_json::to_value(&task).unwrap())
+    }
+
+    async fn set_push_config(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: TaskPushNotificationConfig = parse_params(params)?;
+
+        self.task_store
+            .set_push_config(&params.id, params.push_notification_config.clone())
+            .await
+            .map_err(|e| {
+                JsonRpcError::internal_error(format!("Failed to store push config: {}", e))
+            })?;
 
-        JsonRpcResponse::success(id, serde_json::to_value(&task).unwrap())
+        Ok(serde_json::to_value(&params).unwrap())
     }
 
-    async fn handle_task_get(&self, request: JsonRpcRequest, id: RequestId) -> JsonRpcResponse {
-        let params: TaskQueryParams = match request.params {
+    async fn get_push_config(&self, params: Option<Value>) -> Result<Value, JsonRpcError> {
+        let params: TaskIdParams = parse_params(params)?;
+
+        match self.task_store.get_push_config(&params.id).await {
+            Ok(Some(config)) => Ok(serde_json::to_value(TaskPushNotificationConfig {
+                id: params.id,
+                push_notification_config: config,
+            })
+            .unwrap()),
+            Ok(None) => Err(JsonRpcError::task_not_found(format!(
+                "No push notification config for task: {}",
+                params.id
+            ))),
+            Err(e) => Err(JsonRpcError::internal_error(format!(
+                "Failed to get push config: {}",
+                e
+            ))),
+        }
+    }
+
+    async fn handle_message_stream(
+        &self,
+        request: JsonRpcRequest,
+        id: RequestId,
+    ) -> Result<(String, broadcast::Receiver<AgentEvent>), JsonRpcResponse> {
+        let params: TaskSendParams = match request.params {
             Some(params) => match serde_json::from_value(params) {
                 Ok(p) => p,
                 Err(e) => {
-                    return JsonRpcResponse::error(
+                    return Err(JsonRpcResponse::error(
                         id,
                         JsonRpcError::invalid_params(format!("Invalid parameters: {}", e)),
-                    )
+                    ))
                 }
             },
             None => {
-                return JsonRpcResponse::error(
+                return Err(JsonRpcResponse::error(
                     id,
                     JsonRpcError::invalid_params("Missing parameters"),
-                )
+                ))
             }
         };
 
-        match self.task_store.get_task(&params.base.id).await {
-            Ok(Some(task)) => JsonRpcResponse::success(id, serde_json::to_value(&task).unwrap()),
-            Ok(None) => {
-                JsonRpcResponse::error(id, JsonRpcError::task_not_found("Task not found"))
-            }
-            Err(e) => JsonRpcResponse::error(
+        let task = Task::new(params.id.clone());
+
+        if let Err(e) = self
+            .task_store
+            .store_message(&task.id, params.message.clone())
+            .await
+        {
+            return Err(JsonRpcResponse::error(
                 id,
-                JsonRpcError::internal_error(format!("Failed to get task: {}", e)),
-            ),
+                JsonRpcError::internal_error(format!("Failed to store message: {}", e)),
+            ));
         }
+
+        if let Err(e) = self.task_store.store_task(task.clone()).await {
+            return Err(JsonRpcResponse::error(
+                id,
+                JsonRpcError::internal_error(format!("Failed to store task: {}", e)),
+            ));
+        }
+
+        if let Err(e) = self
+            .store_inline_push_config(&task.id, &params.push_notification)
+            .await
+        {
+            return Err(JsonRpcResponse::error(id, e));
+        }
+
+        let history = self
+            .task_store
+            .get_history(&task.id)
+            .await
+            .unwrap_or_default();
+
+        let context = RequestContext {
+            task_id: params.id.clone(),
+            session_id: params.session_id.clone(),
+            message: params.message.clone(),
+            history,
+            pending_inputs: self.pending_inputs.clone(),
+        };
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let event_queue = EventQueue::new(tx);
+
+        let (broadcast_tx, broadcast_rx) = self.open_stream(params.id.clone()).await;
+
+        let executor = Arc::clone(&self.executor);
+        let task_store = Arc::clone(&self.task_store);
+        let notifier = Arc::clone(&self.notifier);
+        let streams = Arc::clone(&self.streams);
+        let done_waiters = Arc::clone(&self.done_waiters);
+        let task_id = params.id.clone();
+
+        tokio::spawn(async move {
+            process_agent_events(
+                executor,
+                task_store,
+                notifier,
+                context,
+                event_queue,
+                rx,
+                task_id.clone(),
+                Some(broadcast_tx),
+                done_waiters,
+            )
+            .await;
+            streams.write().await.remove(&task_id);
+        });
+
+        Ok((params.id, broadcast_rx))
     }
 
-    async fn handle_task_cancel(&self, request: JsonRpcRequest, id: RequestId) -> JsonRpcResponse {
+    async fn handle_tasks_resubscribe(
+        &self,
+        request: JsonRpcRequest,
+        id: RequestId,
+    ) -> Result<(String, broadcast::Receiver<AgentEvent>), JsonRpcResponse> {
         let params: TaskIdParams = match request.params {
             Some(params) => match serde_json::from_value(params) {
                 Ok(p) => p,
                 Err(e) => {
-                    return JsonRpcResponse::error(
+                    return Err(JsonRpcResponse::error(
                         id,
                         JsonRpcError::invalid_params(format!("Invalid parameters: {}", e)),
-                    )
+                    ))
                 }
             },
             None => {
-                return JsonRpcResponse::error(
+                return Err(JsonRpcResponse::error(
                     id,
                     JsonRpcError::invalid_params("Missing parameters"),
-                )
+                ))
             }
         };
 
-        // Get task
-        let mut task = match self.task_store.get_task(&params.id).await {
-            Ok(Some(task)) => task,
-            Ok(None) => {
-                return JsonRpcResponse::error(id, JsonRpcError::task_not_found("Task not found"))
-            }
-            Err(e) => {
-                return JsonRpcResponse::error(
-                    id,
-                    JsonRpcError::internal_error(format!("Failed to get task: {}", e)),
-                )
-            }
-        };
+        let streams = self.streams.read().await;
+        match streams.get(&params.id) {
+            Some(tx) => Ok((params.id.clone(), tx.subscribe())),
+            None => Err(JsonRpcResponse::error(
+                id,
+                JsonRpcError::task_not_found(format!(
+                    "No in-flight stream for task: {}",
+                    params.id
+                )),
+            )),
+        }
+    }
 
-        // Update task status
-        task.status.state = TaskState::Canceled;
+    /// Get or create the broadcast channel backing a task's SSE stream
+    async fn open_stream(
+        &self,
+        task_id: String,
+    ) -> (broadcast::Sender<AgentEvent>, broadcast::Receiver<AgentEvent>) {
+        let mut streams = self.streams.write().await;
+        let tx = streams
+            .entry(task_id)
+            .or_insert_with(|| broadcast::channel(STREAM_CHANNEL_CAPACITY).0)
+            .clone();
+        let rx = tx.subscribe();
+        (tx, rx)
+    }
+}
 
-        // Store updated task
-        if let Err(e) = self.task_store.update_task(task.clone()).await {
-            return JsonRpcResponse::error(
-                id,
-                JsonRpcError::internal_error(format!("Failed to update task: {}", e)),
-            );
+/// Built-in `message/send` handler
+struct MessageSend;
+
+#[async_trait]
+impl Method for MessageSend {
+    async fn call(&self, params: Option<Value>, state: &RequestHandler) -> Result<Value, JsonRpcError> {
+        state.message_send(params).await
+    }
+}
+
+/// Built-in `tasks/get` handler
+struct TaskGet;
+
+#[async_trait]
+impl Method for TaskGet {
+    async fn call(&self, params: Option<Value>, state: &RequestHandler) -> Result<Value, JsonRpcError> {
+        state.task_get(params).await
+    }
+}
+
+/// Built-in `tasks/cancel` handler
+struct TaskCancel;
+
+#[async_trait]
+impl Method for TaskCancel {
+    async fn call(&self, params: Option<Value>, state: &RequestHandler) -> Result<Value, JsonRpcError> {
+        state.task_cancel(params).await
+    }
+}
+
+/// Built-in `tasks/pushNotificationConfig/set` handler
+struct SetPushConfig;
+
+#[async_trait]
+impl Method for SetPushConfig {
+    async fn call(&self, params: Option<Value>, state: &RequestHandler) -> Result<Value, JsonRpcError> {
+        state.set_push_config(params).await
+    }
+}
+
+/// Built-in `tasks/pushNotificationConfig/get` handler
+struct GetPushConfig;
+
+#[async_trait]
+impl Method for GetPushConfig {
+    async fn call(&self, params: Option<Value>, state: &RequestHandler) -> Result<Value, JsonRpcError> {
+        state.get_push_config(params).await
+    }
+}
+
+/// Run the agent executor and process the resulting events, persisting them to the
+/// task store and, if present, forwarding a copy to a broadcast channel for SSE
+/// subscribers.
+///
+/// The executor runs in its own task, concurrently with the event-draining loop
+/// below, so a status update (in particular `InputRequired`) reaches the task
+/// store and any SSE subscribers while the agent is still executing — e.g.
+/// still parked in `PendingInputs::wait_for_input` — rather than only once
+/// `execute` returns.
+async fn process_agent_events(
+    executor: AgentExecutorRef,
+    task_store: TaskStoreRef,
+    notifier: PushNotificationSenderRef,
+    context: RequestContext,
+    event_queue: EventQueue,
+    mut rx: mpsc::UnboundedReceiver<AgentEvent>,
+    task_id: String,
+    broadcast_tx: Option<broadcast::Sender<AgentEvent>>,
+    done_waiters: DoneWaiters,
+) {
+    let execution = tokio::spawn(async move {
+        if let Err(e) = executor.execute(context, event_queue).await {
+            tracing::error!("Agent execution failed: {}", e);
         }
+    });
 
-        // Call executor cancel
-        let history = self
-            .task_store
-            .get_history(&params.id)
-            .await
-            .unwrap_or_default();
+    // Process events
+    while let Some(event) = rx.recv().await {
+        if let Some(tx) = &broadcast_tx {
+            // No subscribers is not an error; the event is simply dropped.
+            let _ = tx.send(event.clone());
+        }
 
-        let context = RequestContext {
-            task_id: params.id.clone(),
-            session_id: None,
-            message: Message::new("system").with_text("cancel"),
-            history,
-        };
+        match event {
+            AgentEvent::Message(msg) => {
+                // Store message
+                if let Err(e) = task_store.store_message(&task_id, msg.clone()).await {
+                    tracing::error!("Failed to store message: {}", e);
+                }
 
-        if let Err(e) = self.executor.cancel(context).await {
-            tracing::error!("Failed to cancel task: {}", e);
+                // Update task with artifact
+                if let Ok(Some(mut task)) = task_store.get_task(&task_id).await {
+                    let artifact = message_to_artifact(msg);
+                    task = task.with_artifact(artifact);
+                    if let Err(e) = task_store.update_task(task).await {
+                        tracing::error!("Failed to update task: {}", e);
+                    }
+                }
+            }
+            AgentEvent::StatusUpdate(updated_task) => {
+                if let Err(e) = task_store.update_task(updated_task.clone()).await {
+                    tracing::error!("Failed to update task: {}", e);
+                }
+                notify_if_configured(&task_store, &notifier, &task_id, &updated_task, false).await;
+            }
+            AgentEvent::Completed(completed_task) => {
+                if let Err(e) = task_store.update_task(completed_task.clone()).await {
+                    tracing::error!("Failed to update task: {}", e);
+                }
+                notify_if_configured(&task_store, &notifier, &task_id, &completed_task, true).await;
+                break;
+            }
+            AgentEvent::Failed(error) => {
+                if let Ok(Some(mut task)) = task_store.get_task(&task_id).await {
+                    task.status.state = TaskState::Failed;
+                    if let Err(e) = task_store.update_task(task.clone()).await {
+                        tracing::error!("Failed to update task: {}", e);
+                    }
+                    notify_if_configured(&task_store, &notifier, &task_id, &task, true).await;
+                }
+                tracing::error!("Task failed: {}", error);
+                break;
+            }
+            AgentEvent::InputRequired {
+                task_id: event_task_id,
+                prompt,
+                correlation_id,
+            } => {
+                if let Ok(Some(task)) = task_store.get_task(&event_task_id).await {
+                    let task = task.await_input(prompt);
+                    if let Err(e) = task_store.update_task(task.clone()).await {
+                        tracing::error!("Failed to update task: {}", e);
+                    }
+                    notify_if_configured(&task_store, &notifier, &event_task_id, &task, false)
+                        .await;
+                }
+                tracing::debug!(
+                    "Task {} is awaiting input (correlation_id: {})",
+                    event_task_id,
+                    correlation_id
+                );
+                // Not terminal: keep draining. The agent is parked in
+                // `PendingInputs::wait_for_input` and will resume once a
+                // follow-up `tasks/send` for this task arrives. Wake whoever's
+                // waiting on it now, though — otherwise the caller that's
+                // waiting for the prompt (message/send, or a tasks/send that
+                // just resumed it) blocks for the full send_timeout instead of
+                // getting the InputRequired task back right away.
+                notify_done_waiters(&done_waiters, &event_task_id).await;
+            }
+        }
+    }
+
+    if let Err(e) = execution.await {
+        tracing::error!("Agent execution task panicked: {}", e);
+    }
+
+    // Signal everyone still waiting on this task (it reached a terminal state
+    // without ever going through the InputRequired wake-up above).
+    notify_done_waiters(&done_waiters, &task_id).await;
+}
+
+/// Wake everyone currently waiting on `task_id` (the original `message/send`
+/// caller, and any `tasks/send` calls that resumed it from `InputRequired`),
+/// removing them from `done_waiters` so they're each woken exactly once.
+async fn notify_done_waiters(done_waiters: &DoneWaiters, task_id: &str) {
+    if let Some(waiters) = done_waiters.write().await.remove(task_id) {
+        for done_tx in waiters {
+            // The receiving end may already be gone (e.g. its send_timeout fired
+            // first); that's not an error we need to report.
+            let _ = done_tx.send(());
         }
+    }
+}
 
-        JsonRpcResponse::success(id, serde_json::to_value(&task).unwrap())
+/// Deliver a push notification for a task update if a webhook is registered for it
+async fn notify_if_configured(
+    task_store: &TaskStoreRef,
+    notifier: &PushNotificationSenderRef,
+    task_id: &str,
+    task: &Task,
+    is_final: bool,
+) {
+    if let Ok(Some(config)) = task_store.get_push_config(task_id).await {
+        let notifier = Arc::clone(notifier);
+        let notification = TaskNotification {
+            id: task_id.to_string(),
+            status: task.status.clone(),
+            is_final,
+        };
+        tokio::spawn(async move {
+            notifier.notify(&config, &notification).await;
+        });
     }
 }
 
@@ -278,3 +822,70 @@ fn message_to_artifact(message: Message) -> Artifact {
         last_chunk: Some(true),
     }
 }
+
+/// Whether an event marks the end of a task's event stream
+pub(crate) fn is_final_event(event: &AgentEvent) -> bool {
+    matches!(event, AgentEvent::Completed(_) | AgentEvent::Failed(_))
+}
+
+/// Wrap an `AgentEvent` into the JSON-RPC streaming envelope sent over SSE
+pub(crate) fn event_to_streaming_response(task_id: &str, event: &AgentEvent) -> StreamingResponse {
+    match event {
+        AgentEvent::Message(msg) => {
+            let artifact = message_to_artifact(msg.clone());
+            let update = TaskArtifactUpdateEvent {
+                id: task_id.to_string(),
+                artifact,
+                final_: None,
+                metadata: None,
+            };
+            StreamingResponse {
+                result: Some(serde_json::to_value(update).unwrap()),
+                error: None,
+            }
+        }
+        AgentEvent::StatusUpdate(task) => {
+            let update = TaskStatusUpdateEvent {
+                id: task_id.to_string(),
+                status: task.status.clone(),
+                final_: Some(false),
+                metadata: None,
+            };
+            StreamingResponse {
+                result: Some(serde_json::to_value(update).unwrap()),
+                error: None,
+            }
+        }
+        AgentEvent::Completed(task) => {
+            let update = TaskStatusUpdateEvent {
+                id: task_id.to_string(),
+                status: task.status.clone(),
+                final_: Some(true),
+                metadata: None,
+            };
+            StreamingResponse {
+                result: Some(serde_json::to_value(update).unwrap()),
+                error: None,
+            }
+        }
+        AgentEvent::Failed(error) => StreamingResponse {
+            result: None,
+            error: Some(JsonRpcError::internal_error(error.clone())),
+        },
+        AgentEvent::InputRequired { prompt, .. } => {
+            let update = TaskStatusUpdateEvent {
+                id: task_id.to_string(),
+                status: TaskStatus {
+                    state: TaskState::InputRequired,
+                    message: Some(prompt.clone()),
+                },
+                final_: Some(false),
+                metadata: None,
+            };
+            StreamingResponse {
+                result: Some(serde_json::to_value(update).unwrap()),
+                error: None,
+            }
+        }
+    }
+}
@@ -8,6 +8,9 @@
 //! - `A2AServer`: HTTP server built on Axum
 //! - `TaskStore` trait: Persist tasks and message history
 //! - `InMemoryTaskStore`: Simple in-memory task storage
+//! - `SqliteTaskStore`: Durable task storage backed by SQLite
+//! - `RedisTaskStore`: Durable task storage backed by Redis, shareable across instances
+//! - `register_typed_method`: Route a JSON-RPC method to a typed `Params`/`State` handler
 //!
 //! ## Quick Start
 //!
@@ -61,12 +64,29 @@
 //! }
 //! ```
 
+pub mod auth;
 pub mod executor;
 pub mod handler;
+pub mod notifier;
+pub mod redis_store;
+pub mod registry;
 pub mod server;
+pub mod sqlite_store;
 pub mod store;
 
 // Re-export commonly used types
-pub use executor::{AgentEvent, AgentExecutor, AgentExecutorRef, EventQueue, RequestContext};
+pub use auth::{StaticTokenValidator, TokenValidator, TokenValidatorRef};
+pub use executor::{
+    AgentEvent, AgentExecutor, AgentExecutorRef, EventQueue, PendingInputs, RequestContext,
+};
+pub use notifier::{
+    PushNotificationSender, PushNotificationSenderRef, PushNotifier, TaskNotification,
+};
+pub use redis_store::RedisTaskStore;
+pub use registry::{
+    parse_params, ErrorLike, Method, MethodRef, MethodRegistry, Params, RouterState, State,
+    TypedMethod,
+};
 pub use server::A2AServer;
+pub use sqlite_store::SqliteTaskStore;
 pub use store::{InMemoryTaskStore, TaskStore, TaskStoreRef};
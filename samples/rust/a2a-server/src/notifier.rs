@@ -0,0 +1,112 @@
+use a2a_core::jsonrpc::PushNotificationConfig;
+use a2a_core::types::TaskStatus;
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum number of delivery attempts before giving up on a notification
+const MAX_ATTEMPTS: u32 = 5;
+
+/// Initial delay between retries, doubled after each failed attempt
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
+/// Header carrying the webhook's verification token, alongside the standard
+/// bearer `Authorization` header, for receivers that check either convention
+const NOTIFICATION_TOKEN_HEADER: &str = "X-A2A-Notification-Token";
+
+/// A task status change delivered to a registered push notification webhook
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskNotification {
+    /// ID of the task that changed
+    pub id: String,
+    /// The task's new status
+    pub status: TaskStatus,
+    /// Whether this status is terminal (`Completed`/`Failed`) for the task
+    #[serde(rename = "final")]
+    pub is_final: bool,
+}
+
+/// Delivers task status notifications to a client-registered webhook
+///
+/// Implement this to plug in an alternate transport (e.g. a message queue) in
+/// place of the default `reqwest`-based HTTP delivery.
+#[async_trait]
+pub trait PushNotificationSender: Send + Sync {
+    async fn notify(&self, config: &PushNotificationConfig, notification: &TaskNotification);
+}
+
+/// Type alias for Arc-wrapped push notification sender
+pub type PushNotificationSenderRef = Arc<dyn PushNotificationSender>;
+
+/// Default `reqwest`-based `PushNotificationSender`
+#[derive(Clone)]
+pub struct PushNotifier {
+    client: reqwest::Client,
+}
+
+impl Default for PushNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PushNotifier {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl PushNotificationSender for PushNotifier {
+    /// POST the notification to the configured webhook, retrying transient
+    /// (non-2xx or network) failures with exponential backoff.
+    async fn notify(&self, config: &PushNotificationConfig, notification: &TaskNotification) {
+        let mut backoff = INITIAL_BACKOFF;
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            let mut request = self.client.post(&config.url).json(notification);
+            if let Some(token) = &config.token {
+                request = request
+                    .bearer_auth(token)
+                    .header(NOTIFICATION_TOKEN_HEADER, token);
+            }
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => return,
+                Ok(response) => {
+                    tracing::warn!(
+                        "Push notification to {} returned {} (attempt {}/{})",
+                        config.url,
+                        response.status(),
+                        attempt,
+                        MAX_ATTEMPTS
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Push notification to {} failed: {} (attempt {}/{})",
+                        config.url,
+                        e,
+                        attempt,
+                        MAX_ATTEMPTS
+                    );
+                }
+            }
+
+            if attempt < MAX_ATTEMPTS {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+
+        tracing::error!(
+            "Giving up on push notification to {} after {} attempts",
+            config.url,
+            MAX_ATTEMPTS
+        );
+    }
+}
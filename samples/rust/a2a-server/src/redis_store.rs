@@ -0,0 +1,104 @@
+use crate::store::TaskStore;
+use a2a_core::jsonrpc::PushNotificationConfig;
+use a2a_core::types::{Message, Task};
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+
+/// Redis-backed implementation of `TaskStore`
+///
+/// Tasks are stored as a single JSON blob under `task:{id}`, message history as an
+/// append-only list under `history:{id}`, and push notification config as a JSON
+/// blob under `push_config:{id}`, so tasks and history survive process restarts and
+/// can be shared across server instances pointed at the same Redis server.
+pub struct RedisTaskStore {
+    conn: ConnectionManager,
+}
+
+impl RedisTaskStore {
+    /// Connect to Redis (e.g. `redis://127.0.0.1/`)
+    pub async fn connect(redis_url: &str) -> anyhow::Result<Self> {
+        let client = redis::Client::open(redis_url)?;
+        let conn = ConnectionManager::new(client).await?;
+        Ok(Self { conn })
+    }
+
+    fn task_key(id: &str) -> String {
+        format!("task:{id}")
+    }
+
+    fn history_key(id: &str) -> String {
+        format!("history:{id}")
+    }
+
+    fn push_config_key(id: &str) -> String {
+        format!("push_config:{id}")
+    }
+}
+
+#[async_trait]
+impl TaskStore for RedisTaskStore {
+    async fn store_task(&self, task: Task) -> anyhow::Result<()> {
+        let mut conn = self.conn.clone();
+        let value = serde_json::to_string(&task)?;
+        conn.set(Self::task_key(&task.id), value).await?;
+        Ok(())
+    }
+
+    async fn get_task(&self, id: &str) -> anyhow::Result<Option<Task>> {
+        let mut conn = self.conn.clone();
+        let value: Option<String> = conn.get(Self::task_key(id)).await?;
+        value.map(|v| Ok(serde_json::from_str(&v)?)).transpose()
+    }
+
+    async fn update_task(&self, task: Task) -> anyhow::Result<()> {
+        self.store_task(task).await
+    }
+
+    async fn delete_task(&self, id: &str) -> anyhow::Result<()> {
+        let mut conn = self.conn.clone();
+        let keys = vec![
+            Self::task_key(id),
+            Self::history_key(id),
+            Self::push_config_key(id),
+        ];
+        conn.del::<_, ()>(keys).await?;
+        Ok(())
+    }
+
+    async fn store_message(&self, task_id: &str, message: Message) -> anyhow::Result<()> {
+        let mut conn = self.conn.clone();
+        let value = serde_json::to_string(&message)?;
+        conn.rpush::<_, _, ()>(Self::history_key(task_id), value).await?;
+        Ok(())
+    }
+
+    async fn get_history(&self, task_id: &str) -> anyhow::Result<Vec<Message>> {
+        let mut conn = self.conn.clone();
+        let values: Vec<String> = conn.lrange(Self::history_key(task_id), 0, -1).await?;
+        values
+            .iter()
+            .map(|v| Ok(serde_json::from_str(v)?))
+            .collect()
+    }
+
+    async fn set_push_config(
+        &self,
+        task_id: &str,
+        config: PushNotificationConfig,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.conn.clone();
+        let value = serde_json::to_string(&config)?;
+        conn.set(Self::push_config_key(task_id), value).await?;
+        Ok(())
+    }
+
+    async fn get_push_config(
+        &self,
+        task_id: &str,
+    ) -> anyhow::Result<Option<PushNotificationConfig>> {
+        let mut conn = self.conn.clone();
+        let value: Option<String> = conn.get(Self::push_config_key(task_id)).await?;
+        value.map(|v| Ok(serde_json::from_str(&v)?)).transpose()
+    }
+}
@@ -0,0 +1,159 @@
+use crate::executor::AgentExecutorRef;
+use crate::handler::RequestHandler;
+use crate::store::TaskStoreRef;
+use a2a_core::jsonrpc::JsonRpcError;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A single JSON-RPC method, dispatched by name from the method registry
+///
+/// Implement this to expose a custom RPC method on an `A2AServer` alongside the
+/// built-in `message/send`, `tasks/get`, `tasks/cancel`, and push-notification-config
+/// handlers. `state` gives access to the same executor and task store those
+/// built-ins use.
+#[async_trait]
+pub trait Method: Send + Sync {
+    async fn call(
+        &self,
+        params: Option<Value>,
+        state: &RequestHandler,
+    ) -> Result<Value, JsonRpcError>;
+}
+
+/// Type alias for Arc-wrapped method handler
+pub type MethodRef = Arc<dyn Method>;
+
+/// Deserialize `params` into `T`, surfacing a uniform `invalid_params` error on
+/// missing or malformed input rather than letting each method hand-roll its own
+/// parsing boilerplate.
+pub fn parse_params<T: DeserializeOwned>(params: Option<Value>) -> Result<T, JsonRpcError> {
+    match params {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|e| JsonRpcError::invalid_params(format!("Invalid parameters: {}", e))),
+        None => Err(JsonRpcError::invalid_params("Missing parameters")),
+    }
+}
+
+/// Registry mapping JSON-RPC method names to their handlers
+#[derive(Default)]
+pub struct MethodRegistry {
+    methods: HashMap<String, MethodRef>,
+}
+
+impl MethodRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a method, replacing any existing handler registered under `name`
+    pub fn register(&mut self, name: impl Into<String>, method: MethodRef) {
+        self.methods.insert(name.into(), method);
+    }
+
+    /// Look up the handler registered for `name`, if any
+    pub fn get(&self, name: &str) -> Option<MethodRef> {
+        self.methods.get(name).cloned()
+    }
+}
+
+/// Deserialized JSON-RPC `params` handed to a typed method handler
+#[derive(Debug, Clone)]
+pub struct Params<T>(pub T);
+
+/// Shared state handed to typed method handlers: the same executor and task
+/// store backing the built-in A2A methods
+pub struct RouterState {
+    pub executor: AgentExecutorRef,
+    pub task_store: TaskStoreRef,
+}
+
+/// Arc-wrapped handle to a typed method handler's shared state
+pub struct State<S = RouterState>(pub Arc<S>);
+
+impl<S> Clone for State<S> {
+    fn clone(&self) -> Self {
+        State(Arc::clone(&self.0))
+    }
+}
+
+/// Maps a handler's own error type into a `JsonRpcError`, so typed handlers can
+/// return whatever error type is natural for them instead of hand-building
+/// `JsonRpcError`s.
+pub trait ErrorLike {
+    fn into_json_rpc_error(self) -> JsonRpcError;
+}
+
+impl ErrorLike for JsonRpcError {
+    fn into_json_rpc_error(self) -> JsonRpcError {
+        self
+    }
+}
+
+impl ErrorLike for anyhow::Error {
+    fn into_json_rpc_error(self) -> JsonRpcError {
+        JsonRpcError::internal_error(self.to_string())
+    }
+}
+
+/// Adapts a plain async function `async fn(Params<T>, State<RouterState>) ->
+/// Result<R, E>` into a `Method`: `params` is deserialized into `T` (an
+/// automatic `invalid_params` on failure via `parse_params`), and the
+/// handler's result is serialized or mapped through `ErrorLike`. Registering a
+/// new A2A method this way is a one-handler change, with no `Method` impl to
+/// hand-write.
+pub struct TypedMethod<F, T, R, E, Fut>
+where
+    F: Fn(Params<T>, State<RouterState>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<R, E>> + Send,
+    T: DeserializeOwned + Send,
+    R: Serialize + Send,
+    E: ErrorLike + Send,
+{
+    handler: F,
+    state: State<RouterState>,
+    _marker: PhantomData<fn() -> (T, R, E)>,
+}
+
+impl<F, T, R, E, Fut> TypedMethod<F, T, R, E, Fut>
+where
+    F: Fn(Params<T>, State<RouterState>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<R, E>> + Send,
+    T: DeserializeOwned + Send,
+    R: Serialize + Send,
+    E: ErrorLike + Send,
+{
+    pub fn new(handler: F, state: State<RouterState>) -> Self {
+        Self {
+            handler,
+            state,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<F, T, R, E, Fut> Method for TypedMethod<F, T, R, E, Fut>
+where
+    F: Fn(Params<T>, State<RouterState>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<R, E>> + Send,
+    T: DeserializeOwned + Send,
+    R: Serialize + Send,
+    E: ErrorLike + Send,
+{
+    async fn call(
+        &self,
+        params: Option<Value>,
+        _state: &RequestHandler,
+    ) -> Result<Value, JsonRpcError> {
+        let parsed: T = parse_params(params)?;
+        let result = (self.handler)(Params(parsed), self.state.clone()).await;
+        let value = result.map_err(ErrorLike::into_json_rpc_error)?;
+        serde_json::to_value(value).map_err(|e| JsonRpcError::internal_error(e.to_string()))
+    }
+}
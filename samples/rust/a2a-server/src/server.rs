@@ -1,16 +1,34 @@
+use crate::auth::TokenValidatorRef;
 use crate::executor::AgentExecutorRef;
-use crate::handler::RequestHandler;
+use crate::handler::{
+    event_to_streaming_response, is_final_event, RequestHandler, DEFAULT_SEND_TIMEOUT,
+};
+use crate::notifier::{PushNotificationSender, PushNotificationSenderRef};
+use crate::registry::{
+    ErrorLike, Method, MethodRef, Params, RouterState, State as MethodState, TypedMethod,
+};
 use crate::store::TaskStoreRef;
-use a2a_core::jsonrpc::{JsonRpcRequest, JsonRpcResponse};
+use a2a_core::jsonrpc::{JsonRpcError, JsonRpcMessage, JsonRpcRequest, JsonRpcResponse, RequestId};
 use a2a_core::types::AgentCard;
 use axum::{
-    extract::State,
-    http::StatusCode,
-    response::{IntoResponse, Response},
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{
+        sse::{Event, Sse},
+        IntoResponse, Response,
+    },
     routing::{get, post},
     Json, Router,
 };
+use futures::stream::{self, Stream};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::convert::Infallible;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
 use tower_http::cors::CorsLayer;
 
 /// A2A server state
@@ -18,6 +36,7 @@ use tower_http::cors::CorsLayer;
 pub struct A2AServerState {
     agent_card: Arc<AgentCard>,
     handler: Arc<RequestHandler>,
+    auth: Option<TokenValidatorRef>,
 }
 
 /// A2A Server
@@ -27,6 +46,10 @@ pub struct A2AServer {
     task_store: TaskStoreRef,
     host: String,
     port: u16,
+    auth: Option<TokenValidatorRef>,
+    custom_methods: Vec<(String, MethodRef)>,
+    send_timeout: Duration,
+    notifier: Option<PushNotificationSenderRef>,
 }
 
 impl A2AServer {
@@ -42,6 +65,10 @@ impl A2AServer {
             task_store,
             host: "0.0.0.0".to_string(),
             port: 9999,
+            auth: None,
+            custom_methods: Vec::new(),
+            send_timeout: DEFAULT_SEND_TIMEOUT,
+            notifier: None,
         }
     }
 
@@ -57,20 +84,84 @@ impl A2AServer {
         self
     }
 
+    /// Protect the `/` and `/stream` routes with a bearer-token validator, leaving
+    /// `/health` and `/agent-card` open
+    pub fn with_auth(mut self, validator: TokenValidatorRef) -> Self {
+        self.auth = Some(validator);
+        self
+    }
+
+    /// Register a custom JSON-RPC method, exposed alongside the built-in A2A methods.
+    /// Registering under an existing method name overrides the built-in.
+    pub fn register_method(mut self, name: impl Into<String>, method: impl Method + 'static) -> Self {
+        self.custom_methods.push((name.into(), Arc::new(method)));
+        self
+    }
+
+    /// Register a typed JSON-RPC method from a plain async function of the form
+    /// `async fn(Params<T>, State<RouterState>) -> Result<R, E>`, exposed alongside
+    /// the built-in A2A methods. Registering under an existing method name overrides
+    /// the built-in. `State<RouterState>` gives the handler this server's executor
+    /// and task store.
+    pub fn register_typed_method<F, T, R, E, Fut>(mut self, name: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(Params<T>, MethodState<RouterState>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        T: DeserializeOwned + Send + 'static,
+        R: Serialize + Send + 'static,
+        E: ErrorLike + Send + 'static,
+    {
+        let state = MethodState(Arc::new(RouterState {
+            executor: Arc::clone(&self.executor),
+            task_store: Arc::clone(&self.task_store),
+        }));
+        self.custom_methods
+            .push((name.into(), Arc::new(TypedMethod::new(handler, state))));
+        self
+    }
+
+    /// How long `message/send` waits for the agent to reach a terminal state before
+    /// returning the task in whatever state it's currently in. Defaults to 30 seconds.
+    pub fn with_send_timeout(mut self, timeout: Duration) -> Self {
+        self.send_timeout = timeout;
+        self
+    }
+
+    /// Replace the default `reqwest`-based push notification transport with a
+    /// custom one
+    pub fn with_push_notifier(mut self, notifier: impl PushNotificationSender + 'static) -> Self {
+        self.notifier = Some(Arc::new(notifier));
+        self
+    }
+
     /// Build and return the Axum router
     pub fn build_router(&self) -> Router {
-        let handler = Arc::new(RequestHandler::new(
+        let mut handler = RequestHandler::with_send_timeout(
             Arc::clone(&self.executor),
             Arc::clone(&self.task_store),
-        ));
+            self.send_timeout,
+        );
+        for (name, method) in &self.custom_methods {
+            handler.register_method(name.clone(), Arc::clone(method));
+        }
+        if let Some(notifier) = &self.notifier {
+            handler.set_notifier(Arc::clone(notifier));
+        }
+        let handler = Arc::new(handler);
 
         let state = A2AServerState {
             agent_card: Arc::new(self.agent_card.clone()),
             handler,
+            auth: self.auth.clone(),
         };
 
-        Router::new()
+        let protected = Router::new()
             .route("/", post(handle_jsonrpc))
+            .route("/stream", post(handle_stream))
+            .layer(middleware::from_fn_with_state(state.clone(), auth_layer));
+
+        Router::new()
+            .merge(protected)
             .route("/agent-card", get(handle_agent_card))
             .route("/health", get(handle_health))
             .with_state(state)
@@ -94,18 +185,81 @@ impl A2AServer {
     }
 }
 
-/// Handle JSON-RPC requests
+/// Enforce bearer-token auth on protected routes when a validator is configured
+async fn auth_layer(State(state): State<A2AServerState>, request: Request, next: Next) -> Response {
+    let Some(validator) = &state.auth else {
+        return next.run(request).await;
+    };
+
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match token {
+        Some(token) if validator.validate(token).await => next.run(request).await,
+        _ => {
+            let body = JsonRpcResponse::error(
+                RequestId::Null,
+                JsonRpcError::unauthorized("Missing or invalid bearer token"),
+            );
+            (StatusCode::UNAUTHORIZED, Json(body)).into_response()
+        }
+    }
+}
+
+/// Handle JSON-RPC requests, including batches (see `RequestHandler::handle_message`)
 async fn handle_jsonrpc(
     State(state): State<A2AServerState>,
-    Json(request): Json<JsonRpcRequest>,
-) -> Result<Json<JsonRpcResponse>, AppError> {
-    tracing::debug!("Received JSON-RPC request: {:?}", request);
+    Json(message): Json<JsonRpcMessage>,
+) -> Result<Response, AppError> {
+    tracing::debug!("Received JSON-RPC message: {:?}", message);
 
-    let response = state.handler.handle(request).await;
+    let response = state.handler.handle_message(message).await;
 
     tracing::debug!("Sending JSON-RPC response: {:?}", response);
 
-    Ok(Json(response))
+    Ok(match response {
+        Some(response) => Json(response).into_response(),
+        None => StatusCode::OK.into_response(),
+    })
+}
+
+/// Handle `message/stream` and `tasks/resubscribe` requests with a Server-Sent Events response
+async fn handle_stream(
+    State(state): State<A2AServerState>,
+    Json(request): Json<JsonRpcRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let (task_id, receiver) = match state.handler.handle_stream(request).await {
+        Ok(pair) => pair,
+        Err(response) => {
+            let event = Event::default().json_data(response).unwrap();
+            return Ok(Sse::new(Box::pin(stream::once(async { Ok(event) })) as _));
+        }
+    };
+
+    // Yield SSE frames until a Completed/Failed event closes out the task, then stop.
+    let stream = stream::unfold((receiver, task_id, false), |(mut rx, task_id, done)| async move {
+        if done {
+            return None;
+        }
+        match rx.recv().await {
+            Ok(event) => {
+                let is_final = is_final_event(&event);
+                let response = event_to_streaming_response(&task_id, &event);
+                let sse_event = Event::default().json_data(response).unwrap();
+                Some((Ok(sse_event), (rx, task_id, is_final)))
+            }
+            Err(broadcast::error::RecvError::Closed) => None,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                let sse_event = Event::default().data(format!("lagged: {} events", skipped));
+                Some((Ok(sse_event), (rx, task_id, false)))
+            }
+        }
+    });
+
+    Ok(Sse::new(Box::pin(stream) as _))
 }
 
 /// Handle agent card requests
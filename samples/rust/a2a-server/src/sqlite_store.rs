@@ -0,0 +1,201 @@
+use crate::store::TaskStore;
+use a2a_core::jsonrpc::PushNotificationConfig;
+use a2a_core::types::{Message, Task};
+use async_trait::async_trait;
+use sqlx::sqlite::{SqlitePoolOptions, SqliteRow};
+use sqlx::{Row, SqlitePool};
+
+/// SQLite-backed implementation of `TaskStore`
+///
+/// Tasks are stored in a `tasks` table (id, serialized status, serialized artifacts)
+/// and message history in an append-only `messages` table ordered by `ordinal`, so
+/// tasks and history survive process restarts and can be shared across instances
+/// pointed at the same database file.
+pub struct SqliteTaskStore {
+    pool: SqlitePool,
+}
+
+impl SqliteTaskStore {
+    /// Connect to a SQLite database (e.g. `sqlite://tasks.db`) and create the schema
+    /// if it doesn't already exist.
+    pub async fn connect(database_url: &str) -> anyhow::Result<Self> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS tasks (
+                id TEXT PRIMARY KEY,
+                status TEXT NOT NULL,
+                artifacts TEXT
+            )",
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS messages (
+                task_id TEXT NOT NULL,
+                ordinal INTEGER NOT NULL,
+                role TEXT NOT NULL,
+                parts TEXT NOT NULL,
+                PRIMARY KEY (task_id, ordinal)
+            )",
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_messages_task_id ON messages (task_id)")
+            .execute(&pool)
+            .await?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS push_configs (
+                task_id TEXT PRIMARY KEY,
+                url TEXT NOT NULL,
+                token TEXT
+            )",
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+}
+
+fn row_to_task(row: SqliteRow) -> anyhow::Result<Task> {
+    let id: String = row.try_get("id")?;
+    let status: String = row.try_get("status")?;
+    let artifacts: Option<String> = row.try_get("artifacts")?;
+
+    Ok(Task {
+        id,
+        status: serde_json::from_str(&status)?,
+        artifacts: artifacts.map(|a| serde_json::from_str(&a)).transpose()?,
+    })
+}
+
+#[async_trait]
+impl TaskStore for SqliteTaskStore {
+    async fn store_task(&self, task: Task) -> anyhow::Result<()> {
+        let status = serde_json::to_string(&task.status)?;
+        let artifacts = task
+            .artifacts
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+
+        sqlx::query("INSERT OR REPLACE INTO tasks (id, status, artifacts) VALUES (?, ?, ?)")
+            .bind(&task.id)
+            .bind(status)
+            .bind(artifacts)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_task(&self, id: &str) -> anyhow::Result<Option<Task>> {
+        let row = sqlx::query("SELECT id, status, artifacts FROM tasks WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(row_to_task).transpose()
+    }
+
+    async fn update_task(&self, task: Task) -> anyhow::Result<()> {
+        self.store_task(task).await
+    }
+
+    async fn delete_task(&self, id: &str) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM tasks WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM messages WHERE task_id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM push_configs WHERE task_id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn store_message(&self, task_id: &str, message: Message) -> anyhow::Result<()> {
+        let parts = serde_json::to_string(&message.parts)?;
+        let next_ordinal: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(ordinal), -1) + 1 FROM messages WHERE task_id = ?",
+        )
+        .bind(task_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        sqlx::query("INSERT INTO messages (task_id, ordinal, role, parts) VALUES (?, ?, ?, ?)")
+            .bind(task_id)
+            .bind(next_ordinal)
+            .bind(&message.role)
+            .bind(parts)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_history(&self, task_id: &str) -> anyhow::Result<Vec<Message>> {
+        let rows = sqlx::query(
+            "SELECT role, parts FROM messages WHERE task_id = ? ORDER BY ordinal ASC",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let role: String = row.try_get("role")?;
+                let parts: String = row.try_get("parts")?;
+                Ok(Message {
+                    role,
+                    parts: serde_json::from_str(&parts)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn set_push_config(
+        &self,
+        task_id: &str,
+        config: PushNotificationConfig,
+    ) -> anyhow::Result<()> {
+        sqlx::query("INSERT OR REPLACE INTO push_configs (task_id, url, token) VALUES (?, ?, ?)")
+            .bind(task_id)
+            .bind(&config.url)
+            .bind(&config.token)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_push_config(
+        &self,
+        task_id: &str,
+    ) -> anyhow::Result<Option<PushNotificationConfig>> {
+        let row = sqlx::query("SELECT url, token FROM push_configs WHERE task_id = ?")
+            .bind(task_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(|row| {
+            Ok(PushNotificationConfig {
+                url: row.try_get("url")?,
+                token: row.try_get("token")?,
+            })
+        })
+        .transpose()
+    }
+}
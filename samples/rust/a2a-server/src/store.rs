@@ -1,15 +1,27 @@
+use a2a_core::jsonrpc::PushNotificationConfig;
 use a2a_core::types::{Message, Task};
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// How often `InMemoryTaskStore`'s background sweeper checks for expired tasks
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Trait for task storage
 #[async_trait]
 pub trait TaskStore: Send + Sync {
     /// Store a task
     async fn store_task(&self, task: Task) -> anyhow::Result<()>;
 
+    /// Store a task that expires after `ttl`; once elapsed it (and its history)
+    /// are treated as absent. Backends without TTL support may fall back to
+    /// `store_task`, preserving current behavior.
+    async fn store_task_with_ttl(&self, task: Task, _ttl: Duration) -> anyhow::Result<()> {
+        self.store_task(task).await
+    }
+
     /// Get a task by ID
     async fn get_task(&self, id: &str) -> anyhow::Result<Option<Task>>;
 
@@ -24,33 +36,159 @@ pub trait TaskStore: Send + Sync {
 
     /// Get message history for a task
     async fn get_history(&self, task_id: &str) -> anyhow::Result<Vec<Message>>;
+
+    /// Register a push notification webhook for a task
+    async fn set_push_config(
+        &self,
+        task_id: &str,
+        config: PushNotificationConfig,
+    ) -> anyhow::Result<()>;
+
+    /// Get the push notification webhook registered for a task, if any
+    async fn get_push_config(
+        &self,
+        task_id: &str,
+    ) -> anyhow::Result<Option<PushNotificationConfig>>;
+
+    /// List all task ids currently stored, used by the default `invalidate` impl.
+    /// Backends without an efficient way to enumerate keys may return an empty list.
+    async fn list_task_ids(&self) -> anyhow::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Remove tasks (and their history) matching `pattern`: an exact task id, a
+    /// `prefix*` glob (e.g. `session:abc*`), or `*` for every entry. Prefix and
+    /// `*` patterns match against `list_task_ids`, so they remove nothing on a
+    /// backend that leaves it at the default empty list.
+    async fn invalidate(&self, pattern: &str) -> anyhow::Result<()> {
+        let ids = if pattern == "*" {
+            self.list_task_ids().await?
+        } else if let Some(prefix) = pattern.strip_suffix('*') {
+            self.list_task_ids()
+                .await?
+                .into_iter()
+                .filter(|id| id.starts_with(prefix))
+                .collect()
+        } else {
+            vec![pattern.to_string()]
+        };
+        for id in ids {
+            self.delete_task(&id).await?;
+        }
+        Ok(())
+    }
 }
 
 /// In-memory implementation of TaskStore
-#[derive(Default)]
 pub struct InMemoryTaskStore {
     tasks: Arc<RwLock<HashMap<String, Task>>>,
     history: Arc<RwLock<HashMap<String, Vec<Message>>>>,
+    push_configs: Arc<RwLock<HashMap<String, PushNotificationConfig>>>,
+    expires_at: Arc<RwLock<HashMap<String, Instant>>>,
+    default_ttl: Option<Duration>,
+}
+
+impl Default for InMemoryTaskStore {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl InMemoryTaskStore {
+    /// Create a store with no default TTL; tasks persist until explicitly
+    /// deleted or invalidated.
     pub fn new() -> Self {
-        Self {
+        Self::with_default_ttl(None)
+    }
+
+    /// Create a store whose entries expire after `default_ttl` (never, if
+    /// `None`) unless a task is stored with an explicit TTL via
+    /// `store_task_with_ttl`. Spawns a background
+    /// sweeper that periodically purges expired entries.
+    pub fn with_default_ttl(default_ttl: Option<Duration>) -> Self {
+        let store = Self {
             tasks: Arc::new(RwLock::new(HashMap::new())),
             history: Arc::new(RwLock::new(HashMap::new())),
+            push_configs: Arc::new(RwLock::new(HashMap::new())),
+            expires_at: Arc::new(RwLock::new(HashMap::new())),
+            default_ttl,
+        };
+        store.spawn_sweeper();
+        store
+    }
+
+    /// Periodically remove tasks, history, and push config for entries whose
+    /// TTL has elapsed
+    fn spawn_sweeper(&self) {
+        let tasks = Arc::clone(&self.tasks);
+        let history = Arc::clone(&self.history);
+        let push_configs = Arc::clone(&self.push_configs);
+        let expires_at = Arc::clone(&self.expires_at);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+            loop {
+                interval.tick().await;
+                let now = Instant::now();
+                let expired: Vec<String> = expires_at
+                    .read()
+                    .await
+                    .iter()
+                    .filter(|(_, expiry)| **expiry <= now)
+                    .map(|(id, _)| id.clone())
+                    .collect();
+                if expired.is_empty() {
+                    continue;
+                }
+                let mut tasks = tasks.write().await;
+                let mut history = history.write().await;
+                let mut push_configs = push_configs.write().await;
+                let mut expires_at = expires_at.write().await;
+                for id in expired {
+                    tasks.remove(&id);
+                    history.remove(&id);
+                    push_configs.remove(&id);
+                    expires_at.remove(&id);
+                }
+            }
+        });
+    }
+
+    async fn is_expired(&self, id: &str) -> bool {
+        matches!(self.expires_at.read().await.get(id), Some(expiry) if *expiry <= Instant::now())
+    }
+
+    async fn store_task_impl(&self, task: Task, ttl: Option<Duration>) -> anyhow::Result<()> {
+        match ttl {
+            Some(ttl) => {
+                self.expires_at
+                    .write()
+                    .await
+                    .insert(task.id.clone(), Instant::now() + ttl);
+            }
+            None => {
+                self.expires_at.write().await.remove(&task.id);
+            }
         }
+        self.tasks.write().await.insert(task.id.clone(), task);
+        Ok(())
     }
 }
 
 #[async_trait]
 impl TaskStore for InMemoryTaskStore {
     async fn store_task(&self, task: Task) -> anyhow::Result<()> {
-        let mut tasks = self.tasks.write().await;
-        tasks.insert(task.id.clone(), task);
-        Ok(())
+        self.store_task_impl(task, self.default_ttl).await
+    }
+
+    async fn store_task_with_ttl(&self, task: Task, ttl: Duration) -> anyhow::Result<()> {
+        self.store_task_impl(task, Some(ttl)).await
     }
 
     async fn get_task(&self, id: &str) -> anyhow::Result<Option<Task>> {
+        if self.is_expired(id).await {
+            return Ok(None);
+        }
         let tasks = self.tasks.read().await;
         Ok(tasks.get(id).cloned())
     }
@@ -62,8 +200,10 @@ impl TaskStore for InMemoryTaskStore {
     }
 
     async fn delete_task(&self, id: &str) -> anyhow::Result<()> {
-        let mut tasks = self.tasks.write().await;
-        tasks.remove(id);
+        self.tasks.write().await.remove(id);
+        self.history.write().await.remove(id);
+        self.push_configs.write().await.remove(id);
+        self.expires_at.write().await.remove(id);
         Ok(())
     }
 
@@ -77,9 +217,37 @@ impl TaskStore for InMemoryTaskStore {
     }
 
     async fn get_history(&self, task_id: &str) -> anyhow::Result<Vec<Message>> {
+        if self.is_expired(task_id).await {
+            return Ok(Vec::new());
+        }
         let history = self.history.read().await;
         Ok(history.get(task_id).cloned().unwrap_or_default())
     }
+
+    async fn set_push_config(
+        &self,
+        task_id: &str,
+        config: PushNotificationConfig,
+    ) -> anyhow::Result<()> {
+        let mut push_configs = self.push_configs.write().await;
+        push_configs.insert(task_id.to_string(), config);
+        Ok(())
+    }
+
+    async fn get_push_config(
+        &self,
+        task_id: &str,
+    ) -> anyhow::Result<Option<PushNotificationConfig>> {
+        if self.is_expired(task_id).await {
+            return Ok(None);
+        }
+        let push_configs = self.push_configs.read().await;
+        Ok(push_configs.get(task_id).cloned())
+    }
+
+    async fn list_task_ids(&self) -> anyhow::Result<Vec<String>> {
+        Ok(self.tasks.read().await.keys().cloned().collect())
+    }
 }
 
 /// Type alias for Arc-wrapped task store
@@ -0,0 +1,114 @@
+//! Integration suite proving behavioral parity across every `TaskStore`
+//! backend: the same assertions run against `InMemoryTaskStore`,
+//! `SqliteTaskStore`, and (when `REDIS_URL` points at a reachable server)
+//! `RedisTaskStore`.
+
+use a2a_core::jsonrpc::PushNotificationConfig;
+use a2a_core::types::{Message, Task};
+use a2a_server::{InMemoryTaskStore, RedisTaskStore, SqliteTaskStore, TaskStore, TaskStoreRef};
+use std::sync::Arc;
+
+async fn assert_store_get_update_delete(store: &TaskStoreRef) {
+    let task_id = "task-1".to_string();
+    let task = Task::new(task_id.clone());
+
+    assert!(store.get_task(&task_id).await.unwrap().is_none());
+
+    store.store_task(task.clone()).await.unwrap();
+    let fetched = store.get_task(&task_id).await.unwrap().unwrap();
+    assert_eq!(fetched.id, task_id);
+
+    let completed = fetched.complete();
+    store.update_task(completed.clone()).await.unwrap();
+    let fetched = store.get_task(&task_id).await.unwrap().unwrap();
+    assert_eq!(fetched.status.state, completed.status.state);
+
+    store.delete_task(&task_id).await.unwrap();
+    assert!(store.get_task(&task_id).await.unwrap().is_none());
+}
+
+async fn assert_history_ordering(store: &TaskStoreRef) {
+    let task_id = "task-2".to_string();
+    assert!(store.get_history(&task_id).await.unwrap().is_empty());
+
+    store
+        .store_message(&task_id, Message::user_text("first"))
+        .await
+        .unwrap();
+    store
+        .store_message(&task_id, Message::agent_text("second"))
+        .await
+        .unwrap();
+    store
+        .store_message(&task_id, Message::user_text("third"))
+        .await
+        .unwrap();
+
+    let history = store.get_history(&task_id).await.unwrap();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].role, "user");
+    assert_eq!(history[1].role, "agent");
+    assert_eq!(history[2].role, "user");
+    assert_eq!(history[0].parts[0].text.as_deref(), Some("first"));
+    assert_eq!(history[1].parts[0].text.as_deref(), Some("second"));
+    assert_eq!(history[2].parts[0].text.as_deref(), Some("third"));
+
+    store.delete_task(&task_id).await.unwrap();
+    assert!(store.get_history(&task_id).await.unwrap().is_empty());
+}
+
+async fn assert_push_config_round_trip(store: &TaskStoreRef) {
+    let task_id = "task-3".to_string();
+    assert!(store.get_push_config(&task_id).await.unwrap().is_none());
+
+    let config = PushNotificationConfig {
+        url: "https://example.com/webhook".to_string(),
+        token: Some("secret".to_string()),
+    };
+    store
+        .set_push_config(&task_id, config.clone())
+        .await
+        .unwrap();
+
+    let fetched = store.get_push_config(&task_id).await.unwrap().unwrap();
+    assert_eq!(fetched.url, config.url);
+    assert_eq!(fetched.token, config.token);
+
+    store.delete_task(&task_id).await.unwrap();
+    assert!(store.get_push_config(&task_id).await.unwrap().is_none());
+}
+
+async fn assert_parity(store: TaskStoreRef) {
+    assert_store_get_update_delete(&store).await;
+    assert_history_ordering(&store).await;
+    assert_push_config_round_trip(&store).await;
+}
+
+#[tokio::test]
+async fn in_memory_store_parity() {
+    assert_parity(Arc::new(InMemoryTaskStore::new())).await;
+}
+
+#[tokio::test]
+async fn sqlite_store_parity() {
+    let store = SqliteTaskStore::connect("sqlite::memory:").await.unwrap();
+    assert_parity(Arc::new(store)).await;
+}
+
+/// Skipped unless `REDIS_URL` points at a reachable Redis server, since this
+/// suite otherwise runs with no external services.
+#[tokio::test]
+async fn redis_store_parity() {
+    let Ok(redis_url) = std::env::var("REDIS_URL") else {
+        eprintln!("skipping redis_store_parity: REDIS_URL not set");
+        return;
+    };
+    let store = match RedisTaskStore::connect(&redis_url).await {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("skipping redis_store_parity: couldn't connect to {redis_url}: {e}");
+            return;
+        }
+    };
+    assert_parity(Arc::new(store)).await;
+}